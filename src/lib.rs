@@ -1,11 +1,144 @@
 use std::{error::Error, fmt::Display, io::{self, BufReader, Cursor, Read}};
 
+/// Common interface for the hash algorithms in this crate, so callers (like
+/// the binary's `--sha256` flag) can drive either one through the same
+/// buffered `update`/`finalize` machinery without caring which it got.
+pub trait MessageDigest {
+    fn update(&mut self, data: &[u8]);
+    fn finalize_hex(&mut self) -> String;
+}
+
+/// Pads `message` in place to a whole number of 64-byte blocks: appends
+/// `0x80`, zero-pads to a 56-byte boundary (spilling into an extra block if
+/// the current one can't also hold the length), then appends the 64-bit
+/// big-endian *bit* length of `total_size`. Shared by every algorithm in
+/// this crate, since SHA-1 and SHA-256 pad identically.
+fn pad_message_bytes(message: &mut Vec<u8>, total_size: usize) -> Result<(), Box<dyn Error>> {
+    let msg_len = message.len();
+    let total_size_64: u64 = total_size.try_into()?;
+    let total_size_bits = total_size_64.checked_mul(8).ok_or_else(|| io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "message too large to encode as a 64-bit bit length",
+    ))?;
+    let total_size_bits_bytes = total_size_bits.to_be_bytes(); // len in bits, split into 8 bytes
+
+    // The 0x80 marker and the 8-byte length must land in the same 64-byte
+    // block as the zero padding; if the marker doesn't leave at least 8 free
+    // bytes behind it (i.e. it falls past byte 56 of its block), the length
+    // spills into an extra all-zero block.
+    let after_marker = msg_len + 1;
+    let new_size = if after_marker % 64 <= 56 {
+        after_marker - (after_marker % 64) + 56
+    } else {
+        after_marker - (after_marker % 64) + 64 + 56
+    };
+
+    message.resize(new_size + 8, 0);
+    message[msg_len] = 0x80;
+    message[new_size..].copy_from_slice(&total_size_bits_bytes);
+
+    Ok(())
+}
+
+/// The 64-byte block buffering shared by every algorithm in this crate:
+/// turns an arbitrary-sized `update()` stream into whole blocks, carrying
+/// any remainder over to the next call, and pads/drains the tail on
+/// `finalize`. SHA-1 and SHA-256 differ only in how they absorb a finished
+/// block, which is why this takes that step as a callback rather than
+/// owning the state words itself.
+struct BlockBuffer {
+    block: [u8; 64],
+    block_len: usize,
+    total_len: u64,
+    // Whether the state words an algorithm derives from this buffer
+    // currently reflect a complete, padded hash. `block_len == 0` is *not*
+    // a safe proxy for this: a block-aligned `update()` also leaves
+    // `block_len` at 0 after flushing, even though padding/finalization
+    // hasn't happened yet.
+    finalized: bool
+}
+
+impl Default for BlockBuffer {
+    fn default() -> Self {
+        BlockBuffer {
+            block: [0u8; 64],
+            block_len: 0,
+            total_len: 0,
+            // A brand-new buffer has no pending data, so its owner's state
+            // words (whatever they are) are trivially "finalized".
+            finalized: true
+        }
+    }
+}
+
+impl BlockBuffer {
+    /// Feeds `data` through the buffer, invoking `ingest_chunk` once per
+    /// full 64-byte block and buffering any remainder for the next call.
+    fn update(&mut self, data: &[u8], mut ingest_chunk: impl FnMut([u8; 64])) {
+        self.finalized = false;
+        self.total_len = self.total_len.wrapping_add(data.len() as u64);
+        let mut offset = 0;
+
+        if self.block_len > 0 {
+            let available = 64 - self.block_len;
+            let take = available.min(data.len());
+            self.block[self.block_len..self.block_len + take].copy_from_slice(&data[..take]);
+            self.block_len += take;
+            offset += take;
+
+            if self.block_len < 64 {
+                return;
+            }
+
+            ingest_chunk(self.block);
+            self.block_len = 0;
+        }
+
+        while data.len() - offset >= 64 {
+            let block: [u8; 64] = data[offset..offset + 64].try_into().unwrap();
+            ingest_chunk(block);
+            offset += 64;
+        }
+
+        let remainder = &data[offset..];
+        self.block[..remainder.len()].copy_from_slice(remainder);
+        self.block_len = remainder.len();
+    }
+
+    /// Pads the buffered tail and feeds the whole block(s) it expands into
+    /// to `ingest_chunk`, then clears the buffer so it's ready for reuse.
+    fn finalize(&mut self, mut ingest_chunk: impl FnMut([u8; 64])) {
+        let mut tail = self.block[..self.block_len].to_vec();
+        let total_len = self.total_len as usize;
+
+        pad_message_bytes(&mut tail, total_len)
+            .expect("pad_message_bytes is infallible for an in-memory buffer");
+
+        for chunk in tail.chunks(64) {
+            let block: [u8; 64] = chunk.try_into().expect("pad_message_bytes must produce 64-byte blocks");
+            ingest_chunk(block);
+        }
+
+        self.block_len = 0;
+        self.total_len = 0;
+        self.finalized = true;
+    }
+
+    /// Panics with `message` unless `finalize` has run since the last
+    /// `update`, so callers can't read a digest that silently reflects
+    /// incomplete (un-padded) state.
+    fn assert_finalized(&self, message: &str) {
+        assert!(self.finalized, "{}", message);
+    }
+}
+
 pub struct SHA1 {
     h0: u32,
     h1: u32,
     h2: u32,
     h3: u32,
-    h4: u32
+    h4: u32,
+    buffer: BlockBuffer
 }
 
 impl SHA1 {
@@ -15,15 +148,71 @@ impl SHA1 {
             h1: 0xefcdab89,
             h2: 0x98badcfe,
             h3: 0x10325476,
-            h4: 0xc3d2e1f0
+            h4: 0xc3d2e1f0,
+            buffer: BlockBuffer::default()
         }
     }
 
+    /// Restores the initial state words so the instance can be reused for a
+    /// fresh `update`/`finalize` cycle.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Formats the current state words as lowercase hex. This reads the
+    /// state as-is and does *not* run padding/finalization, so it only
+    /// reflects a complete hash once `finalize`/`finalize_digest` has run —
+    /// call one of those (not `update` alone) before reading the digest.
     pub fn digest(&self) -> String {
-        format!(
-            "{:08x}{:08x}{:08x}{:08x}{:08x}",
-            self.h0, self.h1, self.h2, self.h3, self.h4
-        )
+        Digest::from(self.finalize_bytes()).to_string()
+    }
+
+    /// Returns the current state words as a raw 20-byte big-endian digest,
+    /// without re-parsing the hex `digest()` produces. Panics if `update`
+    /// has run since the last `finalize`/`finalize_digest`, since the
+    /// result would silently be wrong.
+    pub fn finalize_bytes(&self) -> [u8; 20] {
+        self.buffer.assert_finalized(
+            "finalize_bytes()/digest() called with data that hasn't been finalized; \
+             call finalize() or finalize_digest() first"
+        );
+
+        let mut bytes = [0u8; 20];
+        bytes[0..4].copy_from_slice(&self.h0.to_be_bytes());
+        bytes[4..8].copy_from_slice(&self.h1.to_be_bytes());
+        bytes[8..12].copy_from_slice(&self.h2.to_be_bytes());
+        bytes[12..16].copy_from_slice(&self.h3.to_be_bytes());
+        bytes[16..20].copy_from_slice(&self.h4.to_be_bytes());
+        bytes
+    }
+
+    /// Feeds an arbitrary-sized slice into the running hash, buffering any
+    /// data that doesn't fill a complete 64-byte block until the next call
+    /// (or until `finalize`).
+    pub fn update(&mut self, data: &[u8]) {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.update(data, |block| {
+            self.ingest_chunk(block).expect("ingest_chunk is infallible for a full 64-byte block");
+        });
+        self.buffer = buffer;
+    }
+
+    /// Pads the buffered tail and folds it into the running hash, returning
+    /// the final lowercase hex digest. The instance should not be reused
+    /// without calling `reset` first.
+    pub fn finalize(&mut self) -> String {
+        self.finalize_digest().to_string()
+    }
+
+    /// Like `finalize`, but returns the raw `Digest` instead of its hex form.
+    pub fn finalize_digest(&mut self) -> Digest {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.finalize(|block| {
+            self.ingest_chunk(block).expect("ingest_chunk is infallible for a full 64-byte block");
+        });
+        self.buffer = buffer;
+
+        Digest::from(self.finalize_bytes())
     }
 
     pub fn ingest(&mut self, stream: Vec<u8>) -> Result<(), Box<dyn Error>> {
@@ -89,28 +278,14 @@ impl SHA1 {
     }
 
     pub fn pad_message(&self, message: &mut Vec<u8>, total_size: usize) -> Result<(), Box<dyn Error>> {
-        let msg_len = message.len();
-        let rem = msg_len % 64;
-        let new_size = msg_len - rem + 64; // smooth brain solution v.v
-        let total_size_64: u64 = total_size.try_into()?;
-        let total_size_64_bytes = (total_size_64 * 8).to_be_bytes(); // len in bits, split into 8 bytes
-
-        message.resize(new_size, 0);
-        message[msg_len] = 0x80;
-        message[new_size - 8..].copy_from_slice(&total_size_64_bytes);
-
-        Ok(())
+        pad_message_bytes(message, total_size)
     }
 
     fn prepare_message_schedule(&self, chunk: [u8; 64]) -> Result<[u32; 80], Box<dyn Error>> {
         let mut schedule = [0u32; 80];
-        let mut buf_reader = BufReader::new(Cursor::new(chunk));
 
-        for i in 0..16 {
-            let mut buf = [0u8; 4];
-            let mut chunk = buf_reader.by_ref().take(4);
-            chunk.read(&mut buf)?;
-            schedule[i] = u32::from_be_bytes(buf);
+        for (word, bytes) in schedule.iter_mut().zip(chunk.chunks_exact(4)) {
+            *word = u32::from_be_bytes(bytes.try_into()?);
         }
 
         for i in 16..80 {
@@ -158,12 +333,347 @@ impl SHA1 {
     }
 }
 
+impl Default for SHA1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Display for SHA1 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.digest())
     }
 }
 
+impl MessageDigest for SHA1 {
+    fn update(&mut self, data: &[u8]) {
+        SHA1::update(self, data)
+    }
+
+    fn finalize_hex(&mut self) -> String {
+        self.finalize()
+    }
+}
+
+/// A 160-bit SHA-1 digest, for consumers that want the raw bytes rather than
+/// re-parsing the hex `SHA1::digest()` produces.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Digest([u8; 20]);
+
+impl From<[u8; 20]> for Digest {
+    fn from(bytes: [u8; 20]) -> Self {
+        Digest(bytes)
+    }
+}
+
+impl AsRef<[u8]> for Digest {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Display for Digest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Wraps a `Read` stream, hashing every byte as it's consumed, failing any
+/// read that pushes the running total past an optional `max_size` byte cap,
+/// and, once the stream is exhausted, checking the result against an
+/// optional expected `Digest`. This lets a caller stream
+/// data to its destination while verifying it, discarding the result if the
+/// digest doesn't match rather than trusting it up front.
+pub struct VerifyingReader<R: Read> {
+    inner: R,
+    hasher: SHA1,
+    expected: Option<Digest>,
+    max_size: Option<u64>,
+    total_read: u64,
+    finished: bool,
+    mismatch: Option<String>
+}
+
+impl<R: Read> VerifyingReader<R> {
+    pub fn new(inner: R) -> Self {
+        VerifyingReader {
+            inner,
+            hasher: SHA1::new(),
+            expected: None,
+            max_size: None,
+            total_read: 0,
+            finished: false,
+            mismatch: None
+        }
+    }
+
+    pub fn with_expected_digest(mut self, expected: Digest) -> Self {
+        self.expected = Some(expected);
+        self
+    }
+
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// The digest of everything read so far, once the stream has hit EOF.
+    /// Returns `None` if the stream hasn't been fully consumed yet.
+    pub fn digest(&self) -> Option<Digest> {
+        self.finished.then(|| Digest::from(self.hasher.finalize_bytes()))
+    }
+}
+
+impl<R: Read> Read for VerifyingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Once a mismatch has been detected, keep re-raising it on every
+        // subsequent call instead of falling through to the `finished`
+        // early-return below, which would report a clean `Ok(0)` EOF.
+        if let Some(reason) = &self.mismatch {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, reason.clone()));
+        }
+
+        let bytes_read = self.inner.read(buf)?;
+
+        if bytes_read == 0 {
+            if !self.finished {
+                let actual = self.hasher.finalize_digest();
+                self.finished = true;
+
+                if let Some(expected) = &self.expected {
+                    if actual != *expected {
+                        let reason =
+                            "digest mismatch: stream does not match the expected checksum"
+                                .to_string();
+                        self.mismatch = Some(reason.clone());
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, reason));
+                    }
+                }
+            }
+
+            return Ok(0);
+        }
+
+        self.total_read += bytes_read as u64;
+
+        if let Some(max_size) = self.max_size {
+            if self.total_read > max_size {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("stream exceeded max_size of {} bytes", max_size),
+                ));
+            }
+        }
+
+        self.hasher.update(&buf[..bytes_read]);
+
+        Ok(bytes_read)
+    }
+}
+
+#[allow(non_snake_case)]
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256, the non-deprecated successor to SHA-1. Padding is identical to
+/// SHA-1's (see `pad_message_bytes`); it differs in state size, round count,
+/// per-round constants, and the `Sigma`/`sigma` mixing functions used to
+/// build the message schedule and run the compression round.
+pub struct SHA256 {
+    h0: u32,
+    h1: u32,
+    h2: u32,
+    h3: u32,
+    h4: u32,
+    h5: u32,
+    h6: u32,
+    h7: u32,
+    buffer: BlockBuffer
+}
+
+impl SHA256 {
+    pub fn new() -> Self {
+        SHA256 {
+            h0: 0x6a09e667,
+            h1: 0xbb67ae85,
+            h2: 0x3c6ef372,
+            h3: 0xa54ff53a,
+            h4: 0x510e527f,
+            h5: 0x9b05688c,
+            h6: 0x1f83d9ab,
+            h7: 0x5be0cd19,
+            buffer: BlockBuffer::default()
+        }
+    }
+
+    /// Restores the initial state words so the instance can be reused for a
+    /// fresh `update`/`finalize` cycle.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Formats the current state words as lowercase hex. This reads the
+    /// state as-is and does *not* run padding/finalization, so it only
+    /// reflects a complete hash once `finalize` has run — call that (not
+    /// `update` alone) before reading the digest. Panics if `update` has
+    /// run since the last `finalize`, since the result would silently be
+    /// wrong.
+    pub fn digest(&self) -> String {
+        self.buffer.assert_finalized(
+            "digest() called with data that hasn't been finalized; call finalize() first"
+        );
+
+        format!(
+            "{:08x}{:08x}{:08x}{:08x}{:08x}{:08x}{:08x}{:08x}",
+            self.h0, self.h1, self.h2, self.h3, self.h4, self.h5, self.h6, self.h7
+        )
+    }
+
+    /// Feeds an arbitrary-sized slice into the running hash, buffering any
+    /// data that doesn't fill a complete 64-byte block until the next call
+    /// (or until `finalize`).
+    pub fn update(&mut self, data: &[u8]) {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.update(data, |block| self.ingest_chunk(block));
+        self.buffer = buffer;
+    }
+
+    /// Pads the buffered tail and folds it into the running hash, returning
+    /// the final lowercase hex digest. The instance should not be reused
+    /// without calling `reset` first.
+    pub fn finalize(&mut self) -> String {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.finalize(|block| self.ingest_chunk(block));
+        self.buffer = buffer;
+
+        self.digest()
+    }
+
+    fn ingest_chunk(&mut self, block: [u8; 64]) {
+        let w = self.prepare_message_schedule(block);
+
+        let mut tmp1: u32;
+        let mut tmp2: u32;
+        let mut a = self.h0;
+        let mut b = self.h1;
+        let mut c = self.h2;
+        let mut d = self.h3;
+        let mut e = self.h4;
+        let mut f = self.h5;
+        let mut g = self.h6;
+        let mut h = self.h7;
+
+        for t in 0..64 {
+            tmp1 = h
+                .wrapping_add(self.big_sigma1(e))
+                .wrapping_add(self.ch(e, f, g))
+                .wrapping_add(SHA256_K[t])
+                .wrapping_add(w[t]);
+            tmp2 = self.big_sigma0(a).wrapping_add(self.maj(a, b, c));
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(tmp1);
+            d = c;
+            c = b;
+            b = a;
+            a = tmp1.wrapping_add(tmp2);
+        }
+
+        self.h0 = a.wrapping_add(self.h0);
+        self.h1 = b.wrapping_add(self.h1);
+        self.h2 = c.wrapping_add(self.h2);
+        self.h3 = d.wrapping_add(self.h3);
+        self.h4 = e.wrapping_add(self.h4);
+        self.h5 = f.wrapping_add(self.h5);
+        self.h6 = g.wrapping_add(self.h6);
+        self.h7 = h.wrapping_add(self.h7);
+    }
+
+    fn prepare_message_schedule(&self, block: [u8; 64]) -> [u32; 64] {
+        let mut w = [0u32; 64];
+
+        for (word, bytes) in w.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes(bytes.try_into().unwrap());
+        }
+
+        for t in 16..64 {
+            w[t] = self.small_sigma1(w[t - 2])
+                .wrapping_add(w[t - 7])
+                .wrapping_add(self.small_sigma0(w[t - 15]))
+                .wrapping_add(w[t - 16]);
+        }
+
+        w
+    }
+
+    #[inline]
+    fn ch(&self, x: u32, y: u32, z: u32) -> u32 {
+        (x & y) ^ (!x & z)
+    }
+
+    #[inline]
+    fn maj(&self, x: u32, y: u32, z: u32) -> u32 {
+        (x & y) ^ (x & z) ^ (y & z)
+    }
+
+    #[allow(non_snake_case)]
+    #[inline]
+    fn big_sigma0(&self, x: u32) -> u32 {
+        x.rotate_right(2) ^ x.rotate_right(13) ^ x.rotate_right(22)
+    }
+
+    #[allow(non_snake_case)]
+    #[inline]
+    fn big_sigma1(&self, x: u32) -> u32 {
+        x.rotate_right(6) ^ x.rotate_right(11) ^ x.rotate_right(25)
+    }
+
+    #[inline]
+    fn small_sigma0(&self, x: u32) -> u32 {
+        x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3)
+    }
+
+    #[inline]
+    fn small_sigma1(&self, x: u32) -> u32 {
+        x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
+    }
+}
+
+impl Default for SHA256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for SHA256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.digest())
+    }
+}
+
+impl MessageDigest for SHA256 {
+    fn update(&mut self, data: &[u8]) {
+        SHA256::update(self, data)
+    }
+
+    fn finalize_hex(&mut self) -> String {
+        self.finalize()
+    }
+}
+
 
 #[cfg(test)]
 mod sha1_tests {
@@ -194,6 +704,102 @@ mod sha1_tests {
         assert_eq!(expected, sha1.digest());
     }
 
+    #[test]
+    fn finalize_bytes_works() {
+        let mut sha1 = SHA1::new();
+        sha1.h0 = 0xaaaaaaaa;
+        sha1.h1 = 0xbbbbbbbb;
+        sha1.h2 = 0xcccccccc;
+        sha1.h3 = 0xdddddddd;
+        sha1.h4 = 0xeeeeeeee;
+        let expected: [u8; 20] = [
+            0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xcc, 0xcc,
+            0xcc, 0xcc, 0xdd, 0xdd, 0xdd, 0xdd, 0xee, 0xee, 0xee, 0xee,
+        ];
+        assert_eq!(expected, sha1.finalize_bytes());
+    }
+
+    #[test]
+    fn digest_type_display_matches_hex_digest() {
+        let mut sha1 = SHA1::new();
+        sha1.update(b"test");
+        let hex = sha1.finalize();
+        let digest = Digest::from(sha1.finalize_bytes());
+        assert_eq!(hex, digest.to_string());
+    }
+
+    #[test]
+    fn digest_type_partial_eq_and_as_ref() {
+        let a = Digest::from([1u8; 20]);
+        let b = Digest::from([1u8; 20]);
+        let c = Digest::from([2u8; 20]);
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.as_ref(), [1u8; 20].as_ref());
+    }
+
+    fn test_digest() -> Digest {
+        Digest::from([
+            0xa9, 0x4a, 0x8f, 0xe5, 0xcc, 0xb1, 0x9b, 0xa6, 0x1c, 0x4c,
+            0x08, 0x73, 0xd3, 0x91, 0xe9, 0x87, 0x98, 0x2f, 0xbb, 0xd3,
+        ])
+    }
+
+    #[test]
+    fn verifying_reader_passes_bytes_through_and_computes_digest() {
+        let mut reader = VerifyingReader::new(Cursor::new(b"test".to_vec()));
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).expect("read should succeed");
+
+        assert_eq!(b"test".to_vec(), out);
+        assert_eq!(Some(test_digest()), reader.digest());
+    }
+
+    #[test]
+    fn verifying_reader_accepts_matching_expected_digest() {
+        let mut reader = VerifyingReader::new(Cursor::new(b"test".to_vec()))
+            .with_expected_digest(test_digest());
+        let mut out = Vec::new();
+
+        reader.read_to_end(&mut out).expect("digest should match");
+    }
+
+    #[test]
+    fn verifying_reader_rejects_mismatched_expected_digest() {
+        let mut reader = VerifyingReader::new(Cursor::new(b"test".to_vec()))
+            .with_expected_digest(Digest::from([0u8; 20]));
+        let mut out = Vec::new();
+
+        assert!(reader.read_to_end(&mut out).is_err());
+    }
+
+    #[test]
+    fn verifying_reader_rejects_stream_over_max_size() {
+        let mut reader = VerifyingReader::new(Cursor::new(vec![0u8; 100])).with_max_size(10);
+        let mut buf = [0u8; 100];
+
+        assert!(reader.read(&mut buf).is_err());
+    }
+
+    #[test]
+    fn verifying_reader_keeps_reporting_mismatch_on_retry() {
+        let mut reader = VerifyingReader::new(Cursor::new(b"test".to_vec()))
+            .with_expected_digest(Digest::from([0u8; 20]));
+        let mut buf = [0u8; 16];
+
+        // First read drains the underlying bytes; the mismatch surfaces on
+        // the read that hits EOF.
+        let n = reader.read(&mut buf).expect("reading the buffered bytes should succeed");
+        assert_eq!(4, n);
+        assert!(reader.read(&mut buf).is_err());
+
+        // A caller that calls read() again after the mismatch (instead of
+        // stopping on the first Err, as read_to_end/io::copy would) must
+        // keep seeing the failure rather than a clean Ok(0) EOF.
+        assert!(reader.read(&mut buf).is_err());
+    }
+
     #[test]
     fn ingest_works_1() {
         let mut sha1 = SHA1::new();
@@ -224,6 +830,75 @@ mod sha1_tests {
         assert_eq!("59638ef75030bf4632b9b58d2eb41e20fa2b1f61", sha1.digest());
     }
 
+    #[test]
+    fn update_finalize_works_1() {
+        let mut sha1 = SHA1::new();
+        sha1.update(b"");
+        assert_eq!("da39a3ee5e6b4b0d3255bfef95601890afd80709", sha1.finalize());
+    }
+
+    #[test]
+    fn update_finalize_works_2() {
+        let mut sha1 = SHA1::new();
+        sha1.update(b"test");
+        assert_eq!("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3", sha1.finalize());
+    }
+
+    #[test]
+    fn update_finalize_works_3() {
+        let mut sha1 = SHA1::new();
+        let msg = b"this is a longer message to be digested that causes multiple 512-bit blocks to be processed";
+        sha1.update(msg);
+        assert_eq!("59638ef75030bf4632b9b58d2eb41e20fa2b1f61", sha1.finalize());
+    }
+
+    #[test]
+    fn update_finalize_works_across_multiple_calls() {
+        let mut sha1 = SHA1::new();
+        let msg = b"this is a longer message to be digested that causes multiple 512-bit blocks to be processed";
+
+        for chunk in msg.chunks(7) {
+            sha1.update(chunk);
+        }
+
+        assert_eq!("59638ef75030bf4632b9b58d2eb41e20fa2b1f61", sha1.finalize());
+    }
+
+    #[test]
+    fn reset_works() {
+        let mut sha1 = SHA1::new();
+        sha1.update(b"test");
+        sha1.finalize();
+
+        sha1.reset();
+        sha1.update(b"");
+        assert_eq!("da39a3ee5e6b4b0d3255bfef95601890afd80709", sha1.finalize());
+    }
+
+    #[test]
+    #[should_panic(expected = "hasn't been")]
+    fn digest_after_update_without_finalize_panics_instead_of_lying() {
+        // update() buffers data under 64 bytes rather than hashing it
+        // immediately, so reading digest()/finalize_bytes() without going
+        // through finalize()/finalize_digest() first must not silently
+        // return the untouched initial state words.
+        let mut sha1 = SHA1::new();
+        sha1.update(b"test");
+        sha1.digest();
+    }
+
+    #[test]
+    #[should_panic(expected = "hasn't been")]
+    fn digest_after_block_aligned_update_without_finalize_panics() {
+        // A block-aligned update() flushes block_len back to 0 right after
+        // ingesting the full block, which used to be indistinguishable
+        // from "nothing buffered" — make sure that no longer fools
+        // digest() into skipping the still-pending finalize().
+        let mut sha1 = SHA1::new();
+        sha1.update(&[0u8; 64]);
+        sha1.digest();
+    }
+
     #[test]
     fn pad_message_works_1() {
         let sha1 = SHA1::new();
@@ -286,6 +961,74 @@ mod sha1_tests {
         compare_arrays(&expected, &message);
     }
 
+    #[test]
+    fn pad_message_works_55_bytes() {
+        // exactly fills the 0x80 marker and the 8-byte length into a single block
+        let sha1 = SHA1::new();
+        let mut message = vec![b'a'; 55];
+        let len = message.len();
+        let mut expected = vec![b'a'; 55];
+        expected.resize(64, 0);
+        expected[55] = 0x80;
+        expected[62] = 0x01;
+        expected[63] = 0xb8;
+
+        let _ = sha1.pad_message(&mut message, len);
+        assert_eq!(64, message.len());
+        compare_arrays(&expected, &message);
+    }
+
+    #[test]
+    fn pad_message_works_56_bytes() {
+        // one byte too many for the 0x80 marker and length to share its block
+        let sha1 = SHA1::new();
+        let mut message = vec![b'a'; 56];
+        let len = message.len();
+        let mut expected = vec![b'a'; 56];
+        expected.resize(128, 0);
+        expected[56] = 0x80;
+        expected[126] = 0x01;
+        expected[127] = 0xc0;
+
+        let _ = sha1.pad_message(&mut message, len);
+        assert_eq!(128, message.len());
+        compare_arrays(&expected, &message);
+    }
+
+    #[test]
+    fn pad_message_works_64_bytes() {
+        // a message that is already a whole block still needs a full extra block of padding
+        let sha1 = SHA1::new();
+        let mut message = vec![b'a'; 64];
+        let len = message.len();
+        let mut expected = vec![b'a'; 64];
+        expected.resize(128, 0);
+        expected[64] = 0x80;
+        expected[126] = 0x02;
+        expected[127] = 0x00;
+
+        let _ = sha1.pad_message(&mut message, len);
+        assert_eq!(128, message.len());
+        compare_arrays(&expected, &message);
+    }
+
+    #[test]
+    fn pad_message_works_119_bytes() {
+        // spans a full block plus a second block that again exactly fits marker + length
+        let sha1 = SHA1::new();
+        let mut message = vec![b'a'; 119];
+        let len = message.len();
+        let mut expected = vec![b'a'; 119];
+        expected.resize(128, 0);
+        expected[119] = 0x80;
+        expected[126] = 0x03;
+        expected[127] = 0xb8;
+
+        let _ = sha1.pad_message(&mut message, len);
+        assert_eq!(128, message.len());
+        compare_arrays(&expected, &message);
+    }
+
     #[test]
     fn prepare_message_schedule_works_1() {
         let sha1 = SHA1::new();
@@ -432,3 +1175,98 @@ mod sha1_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod sha256_tests {
+    use super::*;
+
+    #[test]
+    fn digest_works_1() {
+        let mut sha256 = SHA256::new();
+        sha256.h0 = 0x01010101;
+        sha256.h1 = 0x02020202;
+        sha256.h2 = 0x03030303;
+        sha256.h3 = 0x04040404;
+        sha256.h4 = 0x05050505;
+        sha256.h5 = 0x06060606;
+        sha256.h6 = 0x07070707;
+        sha256.h7 = 0x08080808;
+        let expected = "0101010102020202030303030404040405050505060606060707070708080808";
+        assert_eq!(expected, sha256.digest());
+    }
+
+    #[test]
+    fn update_finalize_works_empty() {
+        let mut sha256 = SHA256::new();
+        sha256.update(b"");
+        assert_eq!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            sha256.finalize()
+        );
+    }
+
+    #[test]
+    fn update_finalize_works_abc() {
+        let mut sha256 = SHA256::new();
+        sha256.update(b"abc");
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            sha256.finalize()
+        );
+    }
+
+    #[test]
+    fn update_finalize_works_across_multiple_calls() {
+        let mut sha256 = SHA256::new();
+        let msg = b"this is a longer message to be digested that causes multiple 512-bit blocks to be processed";
+
+        for chunk in msg.chunks(7) {
+            sha256.update(chunk);
+        }
+
+        let mut expected = SHA256::new();
+        expected.update(msg);
+
+        assert_eq!(expected.finalize(), sha256.finalize());
+    }
+
+    #[test]
+    fn reset_works() {
+        let mut sha256 = SHA256::new();
+        sha256.update(b"abc");
+        sha256.finalize();
+
+        sha256.reset();
+        sha256.update(b"");
+        assert_eq!(
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            sha256.finalize()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "hasn't been")]
+    fn digest_after_update_without_finalize_panics_instead_of_lying() {
+        let mut sha256 = SHA256::new();
+        sha256.update(b"abc");
+        sha256.digest();
+    }
+
+    #[test]
+    #[should_panic(expected = "hasn't been")]
+    fn digest_after_block_aligned_update_without_finalize_panics() {
+        let mut sha256 = SHA256::new();
+        sha256.update(&[0u8; 64]);
+        sha256.digest();
+    }
+
+    #[test]
+    fn message_digest_trait_dispatches_to_sha256() {
+        let mut hasher: Box<dyn MessageDigest> = Box::new(SHA256::new());
+        hasher.update(b"abc");
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            hasher.finalize_hex()
+        );
+    }
+}
@@ -1,11 +1,27 @@
-use std::{error::Error, fs, io::{self, BufReader, Read}, process};
+use std::{env, error::Error, fs, io::{self, BufReader, Read}, process};
 
-use sha1sum::SHA1;
+use sha1sum::{MessageDigest, SHA1, SHA256};
 
 const BUFSIZE: usize = 8192;
 
+#[derive(Clone, Copy)]
+enum Algorithm {
+    Sha1,
+    Sha256,
+}
+
+impl Algorithm {
+    fn new_hasher(self) -> Box<dyn MessageDigest> {
+        match self {
+            Algorithm::Sha1 => Box::new(SHA1::new()),
+            Algorithm::Sha256 => Box::new(SHA256::new()),
+        }
+    }
+}
+
 struct Config {
     file_path: Option<String>,
+    algorithm: Algorithm,
 }
 
 impl Config {
@@ -14,9 +30,17 @@ impl Config {
     ) -> Result<Config, &'static str> {
         args.next();
 
-        let file_path = args.next();
+        let mut file_path = None;
+        let mut algorithm = Algorithm::Sha1;
+
+        for arg in args {
+            match arg.as_str() {
+                "--sha256" => algorithm = Algorithm::Sha256,
+                _ => file_path = Some(arg),
+            }
+        }
 
-        Ok(Config { file_path })
+        Ok(Config { file_path, algorithm })
     }
 }
 
@@ -34,40 +58,22 @@ fn get_input_reader(config: Config) -> Result<Box<dyn Read>, io::Error> {
     }
 }
 
-fn read_chunk<T>(stream: &mut T) -> Result<Vec<u8>, Box<dyn Error>>
-where
-    T: Read
-{
-    let mut v: Vec<u8> = vec![0u8; BUFSIZE];
-    let limit: u64 = BUFSIZE.try_into().unwrap();
-    let bytes_read = stream.take(limit).read(&mut v)?;
-    v.truncate(bytes_read);
-    Ok(v)
-}
-
 fn run(config: Config) -> Result<String, Box<dyn Error>> {
-    let mut sha1 = SHA1::new();
-    let input_reader: Box<dyn Read>;
-    let mut total_bytes: usize = 0;
-
-    input_reader = get_input_reader(config)?;
+    let mut hasher = config.algorithm.new_hasher();
+    let input_reader = get_input_reader(config)?;
     let mut buf_input_reader = BufReader::new(input_reader);
+    let mut buf = [0u8; BUFSIZE];
 
     loop {
-        let mut buf = read_chunk(buf_input_reader.by_ref())?;
-        total_bytes += buf.len();
-
-        match buf.len() {
-            BUFSIZE => sha1.ingest(buf)?,
-            0 => break,
-            _ => {
-                sha1.pad_message(&mut buf, total_bytes);
-                sha1.ingest(buf)?;
-            }
+        let bytes_read = buf_input_reader.read(&mut buf)?;
+        if bytes_read == 0 {
+            break;
         }
+
+        hasher.update(&buf[..bytes_read]);
     }
 
-    Ok(sha1.digest())
+    Ok(hasher.finalize_hex())
 }
 
 fn main() {